@@ -1,47 +1,240 @@
-// caelum.rs - HTTP helper library for Rust target (stub)
+// caelum.rs - HTTP helper library for Rust target
 //
-// NOT YET IMPLEMENTED - requires reqwest/hyper + tokio runtime
+// Provides a synchronous HTTP client and a minimal blocking HTTP server.
+// Generated code is synchronous, so every call here wraps a single
+// process-wide tokio runtime (block_on) rather than spinning one up per
+// request - callers never see a future.
 
-pub fn pete(_url: &str) -> ! {
-    unimplemented!("caelum::pete not yet implemented for Rust target")
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use reqwest::Client;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::runtime::Runtime;
+
+/// Response carried back by the client calls (`pete`, `mitte`, `pone`,
+/// `dele`, `muta`, `roga`).
+#[derive(Debug, Clone)]
+pub struct Responsum {
+    pub status: i64,
+    pub capita: HashMap<String, String>,
+    pub corpus: String,
+}
+
+/// Failure from a client call - connection refused, DNS failure, timeout,
+/// TLS error, or a failure while reading the response body (a non-UTF8
+/// body is decoded lossily, not reported here). Generated code gets a chance
+/// to branch on this instead of the process crashing outright.
+#[derive(Debug)]
+pub struct CaelumError(reqwest::Error);
+
+impl fmt::Display for CaelumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "caelum: request failed: {}", self.0)
+    }
 }
 
-pub fn mitte(_url: &str, _corpus: &str) -> ! {
-    unimplemented!("caelum::mitte not yet implemented for Rust target")
+impl std::error::Error for CaelumError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
 }
 
-pub fn pone(_url: &str, _corpus: &str) -> ! {
-    unimplemented!("caelum::pone not yet implemented for Rust target")
+impl From<reqwest::Error> for CaelumError {
+    fn from(err: reqwest::Error) -> Self {
+        CaelumError(err)
+    }
 }
 
-pub fn dele(_url: &str) -> ! {
-    unimplemented!("caelum::dele not yet implemented for Rust target")
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| Runtime::new().expect("caelum: failed to start tokio runtime"))
+}
+
+fn client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(Client::new)
+}
+
+async fn exsequere(
+    modus: reqwest::Method,
+    url: &str,
+    capita: &HashMap<String, String>,
+    corpus: &str,
+) -> Result<Responsum, CaelumError> {
+    let mut req = client().request(modus, url);
+    for (k, v) in capita {
+        req = req.header(k, v);
+    }
+    if !corpus.is_empty() {
+        req = req.body(corpus.to_string());
+    }
+
+    let resp = req.send().await?;
+    let status = resp.status().as_u16() as i64;
+    let capita = resp
+        .headers()
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or_default().to_string()))
+        .collect();
+    let corpus = resp.text().await?;
+
+    Ok(Responsum { status, capita, corpus })
 }
 
-pub fn muta(_url: &str, _corpus: &str) -> ! {
-    unimplemented!("caelum::muta not yet implemented for Rust target")
+pub fn pete(url: &str) -> Result<Responsum, CaelumError> {
+    roga("GET", url, HashMap::new(), "")
+}
+
+pub fn mitte(url: &str, corpus: &str) -> Result<Responsum, CaelumError> {
+    roga("POST", url, HashMap::new(), corpus)
+}
+
+pub fn pone(url: &str, corpus: &str) -> Result<Responsum, CaelumError> {
+    roga("PUT", url, HashMap::new(), corpus)
+}
+
+pub fn dele(url: &str) -> Result<Responsum, CaelumError> {
+    roga("DELETE", url, HashMap::new(), "")
+}
+
+pub fn muta(url: &str, corpus: &str) -> Result<Responsum, CaelumError> {
+    roga("PATCH", url, HashMap::new(), corpus)
 }
 
 pub fn roga(
-    _modus: &str,
-    _url: &str,
-    _capita: std::collections::HashMap<String, String>,
-    _corpus: &str,
-) -> ! {
-    unimplemented!("caelum::roga not yet implemented for Rust target")
+    modus: &str,
+    url: &str,
+    capita: std::collections::HashMap<String, String>,
+    corpus: &str,
+) -> Result<Responsum, CaelumError> {
+    let modus: reqwest::Method = modus.parse().unwrap_or(reqwest::Method::GET);
+    runtime().block_on(exsequere(modus, url, &capita, corpus))
 }
 
-pub fn exspecta<F>(_handler: F, _portus: i64) -> !
+/// Builds the `Responsum` a handler returns to `exspecta` - generated
+/// handlers call this as the final expression of the handler closure.
+pub fn replicatio(
+    status: i64,
+    capita: std::collections::HashMap<String, String>,
+    corpus: &str,
+) -> Responsum {
+    Responsum {
+        status,
+        capita,
+        corpus: corpus.to_string(),
+    }
+}
+
+async fn serve<F>(handler: F, portus: i64) -> !
 where
-    F: Fn() -> (),
+    F: Fn() -> Responsum,
 {
-    unimplemented!("caelum::exspecta not yet implemented for Rust target")
+    let listener = TcpListener::bind(("127.0.0.1", portus as u16))
+        .await
+        .unwrap_or_else(|e| panic!("caelum: failed to bind port {portus}: {e}"));
+
+    loop {
+        let (mut stream, _) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(_) => continue,
+        };
+
+        // Generated handlers don't inspect the request; it is read once, up
+        // to 4096 bytes, before the reply is written. Anything past the
+        // first read is not consumed.
+        let mut buf = [0u8; 4096];
+        let _ = stream.read(&mut buf).await;
+
+        // The happy path is a plain call/return, no unwinding involved;
+        // catch_unwind here only guards against a genuine panic inside the
+        // handler so one bad request can't take down the whole server.
+        let responsum = std::panic::catch_unwind(std::panic::AssertUnwindSafe(&handler))
+            .unwrap_or_else(|_| Responsum {
+                status: 500,
+                capita: HashMap::new(),
+                corpus: "caelum: handler panicked".to_string(),
+            });
+
+        let _ = stream.write_all(scribe_responsum(&responsum).as_bytes()).await;
+    }
 }
 
-pub fn replicatio(
-    _status: i64,
-    _capita: std::collections::HashMap<String, String>,
-    _corpus: &str,
-) -> ! {
-    unimplemented!("caelum::replicatio not yet implemented for Rust target")
+fn scribe_responsum(responsum: &Responsum) -> String {
+    let mut out = format!(
+        "HTTP/1.1 {} {}\r\n",
+        responsum.status,
+        status_ratio(responsum.status)
+    );
+    for (k, v) in &responsum.capita {
+        out.push_str(&format!("{k}: {v}\r\n"));
+    }
+    out.push_str(&format!("Content-Length: {}\r\n\r\n", responsum.corpus.len()));
+    out.push_str(&responsum.corpus);
+    out
+}
+
+fn status_ratio(status: i64) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        _ => "",
+    }
+}
+
+pub fn exspecta<F>(handler: F, portus: i64) -> !
+where
+    F: Fn() -> Responsum,
+{
+    runtime().block_on(serve(handler, portus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_ratio_covers_common_codes() {
+        assert_eq!(status_ratio(200), "OK");
+        assert_eq!(status_ratio(404), "Not Found");
+        assert_eq!(status_ratio(500), "Internal Server Error");
+    }
+
+    #[test]
+    fn status_ratio_unknown_code_is_blank() {
+        assert_eq!(status_ratio(999), "");
+    }
+
+    #[test]
+    fn scribe_responsum_writes_status_line_headers_and_body() {
+        let mut capita = HashMap::new();
+        capita.insert("Content-Type".to_string(), "text/plain".to_string());
+        let responsum = Responsum {
+            status: 200,
+            capita,
+            corpus: "ok".to_string(),
+        };
+
+        let out = scribe_responsum(&responsum);
+
+        assert!(out.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(out.contains("Content-Type: text/plain\r\n"));
+        assert!(out.contains("Content-Length: 2\r\n"));
+        assert!(out.ends_with("\r\n\r\nok"));
+    }
+
+    #[test]
+    fn replicatio_builds_the_given_responsum() {
+        let responsum = replicatio(201, HashMap::new(), "created");
+        assert_eq!(responsum.status, 201);
+        assert_eq!(responsum.corpus, "created");
+    }
 }
@@ -6,6 +6,12 @@
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
+#[cfg(feature = "parallela")]
+use rayon::prelude::*;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
 // =============================================================================
 // TABULA (Map) HELPERS
 // =============================================================================
@@ -65,6 +71,26 @@ where
     map.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
 }
 
+/// Split a map into (kept, removed) by predicate in a single pass (extrahe)
+pub fn tabula_extrahe<K, V, F>(map: &HashMap<K, V>, pred: F) -> (HashMap<K, V>, Vec<(K, V)>)
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    F: Fn(&K, &V) -> bool,
+{
+    let mut kept = HashMap::new();
+    let mut removed = Vec::new();
+    for (k, v) in map {
+        if pred(k, v) {
+            removed.push((k.clone(), v.clone()));
+        }
+        else {
+            kept.insert(k.clone(), v.clone());
+        }
+    }
+    (kept, removed)
+}
+
 // =============================================================================
 // COPIA (Set) HELPERS
 // =============================================================================
@@ -109,6 +135,55 @@ where
     set.iter().cloned().collect()
 }
 
+/// Split a set into (kept, removed) by predicate in a single pass (extrahe)
+pub fn copia_extrahe<T, F>(set: &HashSet<T>, pred: F) -> (HashSet<T>, Vec<T>)
+where
+    T: Clone + Eq + Hash,
+    F: Fn(&T) -> bool,
+{
+    let mut kept = HashSet::new();
+    let mut removed = Vec::new();
+    for item in set {
+        if pred(item) {
+            removed.push(item.clone());
+        }
+        else {
+            kept.insert(item.clone());
+        }
+    }
+    (kept, removed)
+}
+
+/// Is `a` a subset of `b` (subcopia)? Walks the smaller set so the check
+/// is O(min(|a|,|b|)) rather than materializing an intersection.
+pub fn copia_subcopia<T>(a: &HashSet<T>, b: &HashSet<T>) -> bool
+where
+    T: Eq + Hash,
+{
+    if a.len() > b.len() {
+        return false;
+    }
+    a.iter().all(|item| b.contains(item))
+}
+
+/// Is `a` a superset of `b` (supracopia)?
+pub fn copia_supracopia<T>(a: &HashSet<T>, b: &HashSet<T>) -> bool
+where
+    T: Eq + Hash,
+{
+    copia_subcopia(b, a)
+}
+
+/// Do `a` and `b` share no elements (disiuncta)? Walks the smaller set
+/// against the larger for an early exit as soon as a shared element is found.
+pub fn copia_disiuncta<T>(a: &HashSet<T>, b: &HashSet<T>) -> bool
+where
+    T: Eq + Hash,
+{
+    let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    !smaller.iter().any(|item| larger.contains(item))
+}
+
 // =============================================================================
 // LISTA (Vec) HELPERS
 // =============================================================================
@@ -196,3 +271,850 @@ where
     }
     (truthy, falsy)
 }
+
+/// Split a list into (kept, removed) by predicate in a single pass (extrahe).
+/// Unlike `lista_partire`, the keyed `tabula_extrahe`/`copia_extrahe`
+/// counterparts let the removed side be recovered for e.g. logging evicted
+/// cache entries.
+pub fn lista_extrahe<T, F>(list: &[T], pred: F) -> (Vec<T>, Vec<T>)
+where
+    T: Clone,
+    F: Fn(&T) -> bool,
+{
+    let mut kept = Vec::new();
+    let mut removed = Vec::new();
+    for item in list {
+        if pred(item) {
+            removed.push(item.clone());
+        }
+        else {
+            kept.push(item.clone());
+        }
+    }
+    (kept, removed)
+}
+
+/// Sliding windows of width `n` (fenestrae)
+pub fn lista_fenestrae<T>(list: &[T], n: usize) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    if n == 0 || n > list.len() {
+        return Vec::new();
+    }
+    let count = list.len().saturating_sub(n).saturating_add(1);
+    (0..count).map(|start| list[start..start + n].to_vec()).collect()
+}
+
+/// Non-overlapping chunks of width `n`, the final chunk may be shorter (frusta)
+pub fn lista_frusta<T>(list: &[T], n: usize) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    if n == 0 {
+        return Vec::new();
+    }
+    list.chunks(n).map(|chunk| chunk.to_vec()).collect()
+}
+
+/// Zip two lists into pairs, truncating to the shorter (coniuncta)
+pub fn lista_coniuncta<A, B>(a: &[A], b: &[B]) -> Vec<(A, B)>
+where
+    A: Clone,
+    B: Clone,
+{
+    a.iter().cloned().zip(b.iter().cloned()).collect()
+}
+
+/// Cartesian product of two lists, as pairs (productum)
+pub fn lista_productum<A, B>(a: &[A], b: &[B]) -> Vec<(A, B)>
+where
+    A: Clone,
+    B: Clone,
+{
+    a.iter()
+        .flat_map(|x| b.iter().map(move |y| (x.clone(), y.clone())))
+        .collect()
+}
+
+// =============================================================================
+// ORDO (Insertion-ordered Map/Set) HELPERS
+// =============================================================================
+//
+// tabula/copia iterate in whatever order HashMap/HashSet happen to hash
+// into, which makes generated programs hard to test and reproduce. Ordo
+// keeps a dense Vec of entries alongside a hash index into that Vec, so
+// lookups stay O(1) while iteration, in_lista, and merges preserve
+// insertion order (indexmap-style).
+
+/// Insertion-ordered map. Generated code creates one with the public
+/// `OrdoTabula::nova()` constructor and otherwise works with it through the
+/// `ordo_*` free functions below; the other inherent methods are private.
+#[derive(Debug, Clone)]
+pub struct OrdoTabula<K, V> {
+    entries: Vec<(K, V)>,
+    indices: HashMap<K, usize>,
+}
+
+impl<K, V> OrdoTabula<K, V> {
+    pub fn nova() -> Self {
+        OrdoTabula {
+            entries: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    fn longitudo(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn vacua(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entry at a given position, in insertion order.
+    fn indice(&self, index: usize) -> Option<&(K, V)> {
+        self.entries.get(index)
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, (K, V)> {
+        self.entries.iter()
+    }
+}
+
+impl<K, V> OrdoTabula<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Insert or update a key, preserving its original position on update.
+    fn pone(&mut self, key: K, value: V) {
+        if let Some(&idx) = self.indices.get(&key) {
+            self.entries[idx].1 = value;
+        }
+        else {
+            self.indices.insert(key.clone(), self.entries.len());
+            self.entries.push((key, value));
+        }
+    }
+
+    fn cape(&self, key: &K) -> Option<&V> {
+        self.indices.get(key).map(|&idx| &self.entries[idx].1)
+    }
+
+    /// Position of a key, in insertion order.
+    fn index_de(&self, key: &K) -> Option<usize> {
+        self.indices.get(key).copied()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrdoTabula<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut result = OrdoTabula::nova();
+        for (k, v) in iter {
+            result.pone(k, v);
+        }
+        result
+    }
+}
+
+/// Insert or update a key, preserving its original position on update (pone)
+pub fn ordo_pone<K, V>(map: &mut OrdoTabula<K, V>, key: K, value: V)
+where
+    K: Clone + Eq + Hash,
+{
+    map.pone(key, value);
+}
+
+/// Look up a value by key (cape)
+pub fn ordo_cape<'a, K, V>(map: &'a OrdoTabula<K, V>, key: &K) -> Option<&'a V>
+where
+    K: Clone + Eq + Hash,
+{
+    map.cape(key)
+}
+
+/// Number of entries (longitudo)
+pub fn ordo_longitudo<K, V>(map: &OrdoTabula<K, V>) -> usize {
+    map.longitudo()
+}
+
+/// Is the map empty (vacua)?
+pub fn ordo_vacua<K, V>(map: &OrdoTabula<K, V>) -> bool {
+    map.vacua()
+}
+
+/// Entry at a given position, in insertion order (indice)
+pub fn ordo_indice<K, V>(map: &OrdoTabula<K, V>, index: usize) -> Option<&(K, V)> {
+    map.indice(index)
+}
+
+/// Position of a key, in insertion order (indexDe)
+pub fn ordo_index_de<K, V>(map: &OrdoTabula<K, V>, key: &K) -> Option<usize>
+where
+    K: Clone + Eq + Hash,
+{
+    map.index_de(key)
+}
+
+/// Merge two ordered maps, returning a new map (conflata). Entries from
+/// `a` keep their position; entries only in `b` are appended in `b`'s order.
+pub fn ordo_conflata<K, V>(a: &OrdoTabula<K, V>, b: &OrdoTabula<K, V>) -> OrdoTabula<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    let mut result = a.clone();
+    for (k, v) in b.iter() {
+        result.pone(k.clone(), v.clone());
+    }
+    result
+}
+
+/// Swap keys and values (inversa), preserving iteration order.
+pub fn ordo_inversa<K, V>(map: &OrdoTabula<K, V>) -> OrdoTabula<V, K>
+where
+    K: Clone,
+    V: Clone + Eq + Hash,
+{
+    map.iter()
+        .map(|(k, v)| (v.clone(), k.clone()))
+        .collect()
+}
+
+/// Keep only specified keys (selecta), preserving iteration order.
+pub fn ordo_selecta<K, V>(map: &OrdoTabula<K, V>, keys: &[K]) -> OrdoTabula<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    let key_set: HashSet<_> = keys.iter().collect();
+    map.iter()
+        .filter(|(k, _)| key_set.contains(k))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+/// Remove specified keys (omissa), preserving iteration order.
+pub fn ordo_omissa<K, V>(map: &OrdoTabula<K, V>, keys: &[K]) -> OrdoTabula<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    let key_set: HashSet<_> = keys.iter().collect();
+    map.iter()
+        .filter(|(k, _)| !key_set.contains(k))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+/// Convert to list of pairs in insertion order (inLista)
+pub fn ordo_in_lista<K, V>(map: &OrdoTabula<K, V>) -> Vec<(K, V)>
+where
+    K: Clone,
+    V: Clone,
+{
+    map.iter().cloned().collect()
+}
+
+/// Insertion-ordered set. Created with the public `OrdoCopia::nova()`
+/// constructor and otherwise used through the `ordo_copia_*` free functions
+/// below; the other inherent methods are private.
+#[derive(Debug, Clone)]
+pub struct OrdoCopia<T> {
+    entries: Vec<T>,
+    indices: HashMap<T, usize>,
+}
+
+impl<T> OrdoCopia<T> {
+    pub fn nova() -> Self {
+        OrdoCopia {
+            entries: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    fn longitudo(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn vacua(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Element at a given position, in insertion order.
+    fn indice(&self, index: usize) -> Option<&T> {
+        self.entries.get(index)
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.entries.iter()
+    }
+}
+
+impl<T> OrdoCopia<T>
+where
+    T: Clone + Eq + Hash,
+{
+    /// Insert a value; a value already present keeps its original position.
+    fn pone(&mut self, value: T) {
+        if !self.indices.contains_key(&value) {
+            self.indices.insert(value.clone(), self.entries.len());
+            self.entries.push(value);
+        }
+    }
+
+    fn continet(&self, value: &T) -> bool {
+        self.indices.contains_key(value)
+    }
+
+    /// Position of a value, in insertion order.
+    fn index_de(&self, value: &T) -> Option<usize> {
+        self.indices.get(value).copied()
+    }
+}
+
+impl<T> FromIterator<T> for OrdoCopia<T>
+where
+    T: Clone + Eq + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = OrdoCopia::nova();
+        for value in iter {
+            result.pone(value);
+        }
+        result
+    }
+}
+
+/// Insert a value; a value already present keeps its original position (pone)
+pub fn ordo_copia_pone<T>(set: &mut OrdoCopia<T>, value: T)
+where
+    T: Clone + Eq + Hash,
+{
+    set.pone(value);
+}
+
+/// Does the set contain a value (continet)?
+pub fn ordo_copia_continet<T>(set: &OrdoCopia<T>, value: &T) -> bool
+where
+    T: Clone + Eq + Hash,
+{
+    set.continet(value)
+}
+
+/// Number of elements (longitudo)
+pub fn ordo_copia_longitudo<T>(set: &OrdoCopia<T>) -> usize {
+    set.longitudo()
+}
+
+/// Is the set empty (vacua)?
+pub fn ordo_copia_vacua<T>(set: &OrdoCopia<T>) -> bool {
+    set.vacua()
+}
+
+/// Element at a given position, in insertion order (indice)
+pub fn ordo_copia_indice<T>(set: &OrdoCopia<T>, index: usize) -> Option<&T> {
+    set.indice(index)
+}
+
+/// Position of a value, in insertion order (indexDe)
+pub fn ordo_copia_index_de<T>(set: &OrdoCopia<T>, value: &T) -> Option<usize>
+where
+    T: Clone + Eq + Hash,
+{
+    set.index_de(value)
+}
+
+/// Union of two ordered sets (unio): `a`'s elements first, then any of
+/// `b`'s not already present, both in their original order.
+pub fn ordo_copia_unio<T>(a: &OrdoCopia<T>, b: &OrdoCopia<T>) -> OrdoCopia<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut result = a.clone();
+    for value in b.iter() {
+        result.pone(value.clone());
+    }
+    result
+}
+
+/// Convert to list in insertion order (inLista)
+pub fn ordo_copia_in_lista<T>(set: &OrdoCopia<T>) -> Vec<T>
+where
+    T: Clone,
+{
+    set.iter().cloned().collect()
+}
+
+// =============================================================================
+// PARALLELA (Rayon-backed Parallel) HELPERS
+// =============================================================================
+//
+// congrega/unica/unio/intersectio are all single-threaded clones, fine for
+// the small collections most generated programs touch but wasteful once
+// an input grows large. These gate on PARALLELA_SUSCITUM: below it they
+// defer straight to the sequential helper, at or above it they split the
+// work across threads with rayon. Requires the `parallela` feature.
+
+#[cfg(feature = "parallela")]
+const PARALLELA_SUSCITUM: usize = 1_000;
+
+/// Group by key function, splitting across threads once `list` is large
+/// enough to be worth it (parallela). Each thread folds its slice into a
+/// partial `HashMap<K, Vec<T>>`; partials are then merged by appending
+/// vectors on key collision, giving the same shape as `lista_congrega`.
+#[cfg(feature = "parallela")]
+pub fn lista_congrega_parallela<T, K, F>(list: &[T], key_fn: F) -> HashMap<K, Vec<T>>
+where
+    T: Clone + Send + Sync,
+    K: Eq + Hash + Send,
+    F: Fn(&T) -> K + Sync,
+{
+    if list.len() < PARALLELA_SUSCITUM {
+        return lista_congrega(list, key_fn);
+    }
+
+    list.par_iter()
+        .fold(HashMap::<K, Vec<T>>::new, |mut acc, item| {
+            acc.entry(key_fn(item)).or_default().push(item.clone());
+            acc
+        })
+        .reduce(HashMap::new, |mut a, b| {
+            for (k, mut v) in b {
+                a.entry(k).or_default().append(&mut v);
+            }
+            a
+        })
+}
+
+/// Remove duplicates, splitting across threads once `list` is large enough
+/// to be worth it (parallela). Runs `lista_unica` over per-thread chunks,
+/// then de-duplicates the merge in original order.
+#[cfg(feature = "parallela")]
+pub fn lista_unica_parallela<T>(list: &[T]) -> Vec<T>
+where
+    T: Clone + Eq + Hash + Send + Sync,
+{
+    if list.len() < PARALLELA_SUSCITUM {
+        return lista_unica(list);
+    }
+
+    let chunk_size = (list.len() / rayon::current_num_threads().max(1)).max(1);
+    let partials: Vec<Vec<T>> = list.par_chunks(chunk_size).map(lista_unica).collect();
+    lista_unica(&partials.concat())
+}
+
+/// Union of two sets, splitting across threads once the combined size is
+/// large enough to be worth it (parallela).
+#[cfg(feature = "parallela")]
+pub fn copia_unio_parallela<T>(a: &HashSet<T>, b: &HashSet<T>) -> HashSet<T>
+where
+    T: Clone + Eq + Hash + Send + Sync,
+{
+    if a.len() + b.len() < PARALLELA_SUSCITUM {
+        return copia_unio(a, b);
+    }
+
+    a.par_iter().chain(b.par_iter()).cloned().collect()
+}
+
+/// Intersection of two sets, scanning the smaller set against the larger
+/// across threads once the larger set is big enough to be worth it (parallela).
+#[cfg(feature = "parallela")]
+pub fn copia_intersectio_parallela<T>(a: &HashSet<T>, b: &HashSet<T>) -> HashSet<T>
+where
+    T: Clone + Eq + Hash + Send + Sync,
+{
+    if a.len().max(b.len()) < PARALLELA_SUSCITUM {
+        return copia_intersectio(a, b);
+    }
+
+    let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    smaller
+        .par_iter()
+        .filter(|item| larger.contains(*item))
+        .cloned()
+        .collect()
+}
+
+/// Map a list to a new list (transforma)
+pub fn lista_transforma<T, U, F>(list: &[T], f: F) -> Vec<U>
+where
+    F: Fn(&T) -> U,
+{
+    list.iter().map(f).collect()
+}
+
+/// Map a list to a new list, splitting across threads once `list` is large
+/// enough to be worth it (parallela).
+#[cfg(feature = "parallela")]
+pub fn lista_transforma_parallela<T, U, F>(list: &[T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    if list.len() < PARALLELA_SUSCITUM {
+        return lista_transforma(list, f);
+    }
+
+    list.par_iter().map(f).collect()
+}
+
+/// Filter a list (filtra)
+pub fn lista_filtra<T, F>(list: &[T], pred: F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T) -> bool,
+{
+    list.iter().filter(|item| pred(item)).cloned().collect()
+}
+
+/// Filter a list, splitting across threads once `list` is large enough to
+/// be worth it (parallela).
+#[cfg(feature = "parallela")]
+pub fn lista_filtra_parallela<T, F>(list: &[T], pred: F) -> Vec<T>
+where
+    T: Clone + Sync + Send,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    if list.len() < PARALLELA_SUSCITUM {
+        return lista_filtra(list, pred);
+    }
+
+    list.par_iter().filter(|item| pred(item)).cloned().collect()
+}
+
+// =============================================================================
+// SERIALIZATIO (JSON round-trip) HELPERS
+// =============================================================================
+//
+// Lets generated programs persist a tabula/copia/lista to disk or hand it
+// to the new caelum HTTP client. Maps serialize as a JSON object when
+// every key serializes to a JSON string, and fall back to an array of
+// `[key, value]` pairs otherwise (mirroring indexmap's serde behavior for
+// non-string keys). Deserializing malformed input surfaces as an `Err`
+// rather than a panic.
+
+/// Serialize a map to a JSON string (serializa)
+pub fn tabula_serializa<K, V>(map: &HashMap<K, V>) -> Result<String, serde_json::Error>
+where
+    K: Serialize + Eq + Hash,
+    V: Serialize,
+{
+    let mut as_object = serde_json::Map::with_capacity(map.len());
+    let mut stringly = true;
+    for (k, v) in map {
+        if let serde_json::Value::String(key) = serde_json::to_value(k)? {
+            as_object.insert(key, serde_json::to_value(v)?);
+        }
+        else {
+            stringly = false;
+            break;
+        }
+    }
+
+    if stringly {
+        serde_json::to_string(&serde_json::Value::Object(as_object))
+    }
+    else {
+        let pairs: Vec<(&K, &V)> = map.iter().collect();
+        serde_json::to_string(&pairs)
+    }
+}
+
+/// Deserialize a map from a JSON string, accepting either an object (with
+/// string keys) or an array of `[key, value]` pairs (deserializa)
+pub fn tabula_deserializa<K, V>(json: &str) -> Result<HashMap<K, V>, serde_json::Error>
+where
+    K: DeserializeOwned + Eq + Hash,
+    V: DeserializeOwned,
+{
+    match serde_json::from_str(json)? {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .map(|(k, v)| {
+                let key: K = serde_json::from_value(serde_json::Value::String(k))?;
+                let value: V = serde_json::from_value(v)?;
+                Ok((key, value))
+            })
+            .collect(),
+        value => {
+            let pairs: Vec<(K, V)> = serde_json::from_value(value)?;
+            Ok(pairs.into_iter().collect())
+        }
+    }
+}
+
+/// Serialize a set to a JSON array (serializa)
+pub fn copia_serializa<T>(set: &HashSet<T>) -> Result<String, serde_json::Error>
+where
+    T: Serialize + Eq + Hash,
+{
+    let items: Vec<&T> = set.iter().collect();
+    serde_json::to_string(&items)
+}
+
+/// Deserialize a set from a JSON array (deserializa)
+pub fn copia_deserializa<T>(json: &str) -> Result<HashSet<T>, serde_json::Error>
+where
+    T: DeserializeOwned + Eq + Hash,
+{
+    let items: Vec<T> = serde_json::from_str(json)?;
+    Ok(items.into_iter().collect())
+}
+
+/// Serialize a list to a JSON array (serializa)
+pub fn lista_serializa<T>(list: &[T]) -> Result<String, serde_json::Error>
+where
+    T: Serialize,
+{
+    serde_json::to_string(list)
+}
+
+/// Deserialize a list from a JSON array (deserializa)
+pub fn lista_deserializa<T>(json: &str) -> Result<Vec<T>, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tabula_serializa_string_keys_as_object() {
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), 1);
+        assert_eq!(tabula_serializa(&map).unwrap(), r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn tabula_serializa_non_string_keys_as_pairs() {
+        let mut map = HashMap::new();
+        map.insert(1i32, "x".to_string());
+        assert_eq!(tabula_serializa(&map).unwrap(), r#"[[1,"x"]]"#);
+    }
+
+    #[test]
+    fn tabula_roundtrip_string_keys() {
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), 1);
+        map.insert("b".to_string(), 2);
+        let json = tabula_serializa(&map).unwrap();
+        let back: HashMap<String, i32> = tabula_deserializa(&json).unwrap();
+        assert_eq!(back, map);
+    }
+
+    #[test]
+    fn tabula_roundtrip_non_string_keys() {
+        let mut map = HashMap::new();
+        map.insert(1i32, "x".to_string());
+        map.insert(2i32, "y".to_string());
+        let json = tabula_serializa(&map).unwrap();
+        let back: HashMap<i32, String> = tabula_deserializa(&json).unwrap();
+        assert_eq!(back, map);
+    }
+
+    #[test]
+    fn tabula_deserializa_malformed_input_is_err() {
+        let result: Result<HashMap<String, i32>, _> = tabula_deserializa("not json");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn copia_roundtrip() {
+        let set: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let json = copia_serializa(&set).unwrap();
+        let back: HashSet<i32> = copia_deserializa(&json).unwrap();
+        assert_eq!(back, set);
+    }
+
+    #[test]
+    fn lista_roundtrip() {
+        let list = vec![1, 2, 3];
+        let json = lista_serializa(&list).unwrap();
+        let back: Vec<i32> = lista_deserializa(&json).unwrap();
+        assert_eq!(back, list);
+    }
+
+    #[test]
+    fn lista_deserializa_malformed_input_is_err() {
+        let result: Result<Vec<i32>, _> = lista_deserializa("{not valid");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn lista_transforma_maps_each_element() {
+        assert_eq!(lista_transforma(&[1, 2, 3], |n: &i32| n * 2), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn lista_filtra_keeps_matching_elements() {
+        assert_eq!(lista_filtra(&[1, 2, 3, 4], |n: &i32| n % 2 == 0), vec![2, 4]);
+    }
+
+    #[cfg(feature = "parallela")]
+    #[test]
+    fn lista_congrega_parallela_matches_sequential_above_threshold() {
+        let list: Vec<i32> = (0..(PARALLELA_SUSCITUM as i32 * 2)).collect();
+        let parallel = lista_congrega_parallela(&list, |n| n % 3);
+        let sequential = lista_congrega(&list, |n| n % 3);
+        for (k, mut v) in sequential {
+            let mut pv = parallel.get(&k).cloned().unwrap_or_default();
+            v.sort();
+            pv.sort();
+            assert_eq!(v, pv);
+        }
+    }
+
+    #[cfg(feature = "parallela")]
+    #[test]
+    fn lista_unica_parallela_matches_sequential_above_threshold() {
+        let list: Vec<i32> = (0..(PARALLELA_SUSCITUM as i32 * 2)).chain(0..5).collect();
+        let mut parallel = lista_unica_parallela(&list);
+        let mut sequential = lista_unica(&list);
+        parallel.sort();
+        sequential.sort();
+        assert_eq!(parallel, sequential);
+    }
+
+    #[cfg(feature = "parallela")]
+    #[test]
+    fn copia_unio_parallela_matches_sequential_above_threshold() {
+        let a: HashSet<i32> = (0..(PARALLELA_SUSCITUM as i32)).collect();
+        let b: HashSet<i32> =
+            ((PARALLELA_SUSCITUM as i32 / 2)..(PARALLELA_SUSCITUM as i32 * 2)).collect();
+        assert_eq!(copia_unio_parallela(&a, &b), copia_unio(&a, &b));
+    }
+
+    #[cfg(feature = "parallela")]
+    #[test]
+    fn copia_intersectio_parallela_matches_sequential_above_threshold() {
+        let a: HashSet<i32> = (0..(PARALLELA_SUSCITUM as i32)).collect();
+        let b: HashSet<i32> =
+            ((PARALLELA_SUSCITUM as i32 / 2)..(PARALLELA_SUSCITUM as i32 * 2)).collect();
+        assert_eq!(
+            copia_intersectio_parallela(&a, &b),
+            copia_intersectio(&a, &b)
+        );
+    }
+
+    #[test]
+    fn lista_extrahe_splits_kept_from_removed() {
+        let (kept, removed) = lista_extrahe(&[1, 2, 3, 4, 5], |n| n % 2 == 0);
+        assert_eq!(kept, vec![1, 3, 5]);
+        assert_eq!(removed, vec![2, 4]);
+    }
+
+    #[test]
+    fn copia_extrahe_splits_kept_from_removed() {
+        let set: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let (kept, mut removed) = copia_extrahe(&set, |&n| n == 2);
+        removed.sort();
+        assert_eq!(removed, vec![2]);
+        assert!(!kept.contains(&2));
+        assert!(kept.contains(&1) && kept.contains(&3));
+    }
+
+    #[test]
+    fn tabula_extrahe_splits_kept_from_removed() {
+        let map: HashMap<&str, i32> = [("a", 1), ("b", 2)].into_iter().collect();
+        let (kept, removed) = tabula_extrahe(&map, |_, v| *v > 1);
+        assert_eq!(removed, vec![("b", 2)]);
+        assert_eq!(kept.get("a"), Some(&1));
+        assert!(!kept.contains_key("b"));
+    }
+
+    #[test]
+    fn lista_fenestrae_slides_across_the_list() {
+        assert_eq!(
+            lista_fenestrae(&[1, 2, 3, 4], 2),
+            vec![vec![1, 2], vec![2, 3], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn lista_fenestrae_window_larger_than_list_is_empty() {
+        let empty: Vec<Vec<i32>> = Vec::new();
+        assert_eq!(lista_fenestrae(&[1, 2], 3), empty);
+    }
+
+    #[test]
+    fn lista_fenestrae_zero_width_is_empty() {
+        let empty: Vec<Vec<i32>> = Vec::new();
+        assert_eq!(lista_fenestrae(&[1, 2, 3], 0), empty);
+    }
+
+    #[test]
+    fn lista_frusta_final_chunk_may_be_shorter() {
+        assert_eq!(
+            lista_frusta(&[1, 2, 3, 4, 5], 2),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn lista_coniuncta_truncates_to_shorter() {
+        assert_eq!(
+            lista_coniuncta(&[1, 2, 3], &["a", "b"]),
+            vec![(1, "a"), (2, "b")]
+        );
+    }
+
+    #[test]
+    fn lista_productum_is_cartesian() {
+        assert_eq!(
+            lista_productum(&[1, 2], &["a", "b"]),
+            vec![(1, "a"), (1, "b"), (2, "a"), (2, "b")]
+        );
+    }
+
+    #[test]
+    fn ordo_preserves_insertion_order() {
+        let mut map = OrdoTabula::nova();
+        ordo_pone(&mut map, "b", 2);
+        ordo_pone(&mut map, "a", 1);
+        ordo_pone(&mut map, "b", 22);
+
+        assert_eq!(ordo_longitudo(&map), 2);
+        assert_eq!(ordo_in_lista(&map), vec![("b", 22), ("a", 1)]);
+        assert_eq!(ordo_index_de(&map, &"a"), Some(1));
+        assert_eq!(ordo_indice(&map, 0), Some(&("b", 22)));
+        assert_eq!(ordo_cape(&map, &"a"), Some(&1));
+    }
+
+    #[test]
+    fn ordo_conflata_appends_new_keys_after_originals() {
+        let mut a = OrdoTabula::nova();
+        ordo_pone(&mut a, "x", 1);
+        let mut b = OrdoTabula::nova();
+        ordo_pone(&mut b, "y", 2);
+        ordo_pone(&mut b, "x", 9);
+
+        let merged = ordo_conflata(&a, &b);
+
+        assert_eq!(ordo_in_lista(&merged), vec![("x", 9), ("y", 2)]);
+    }
+
+    #[test]
+    fn ordo_copia_unio_preserves_order_and_dedupes() {
+        let mut a = OrdoCopia::nova();
+        ordo_copia_pone(&mut a, 1);
+        ordo_copia_pone(&mut a, 2);
+        let mut b = OrdoCopia::nova();
+        ordo_copia_pone(&mut b, 2);
+        ordo_copia_pone(&mut b, 3);
+
+        let union = ordo_copia_unio(&a, &b);
+
+        assert_eq!(ordo_copia_in_lista(&union), vec![1, 2, 3]);
+        assert!(ordo_copia_continet(&union, &3));
+        assert_eq!(ordo_copia_index_de(&union, &3), Some(2));
+    }
+}